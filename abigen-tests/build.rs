@@ -1,7 +1,7 @@
 use substreams_ethereum::{Abigen, AbiExtension, EventExtension};
 
 fn main() -> Result<(), anyhow::Error> {
-    let abis = vec!["tests"];
+    let abis = vec!["tests", "erc20"];
 
     for abi in abis {
         // All `path` arguments is relative to crate's Cargo.toml directory, in this example, it's 'abigen'
@@ -10,7 +10,19 @@ fn main() -> Result<(), anyhow::Error> {
 
         let abigen = Abigen::new(abi, None, &in_path)?;
         let mut event_extension = EventExtension::new();
-        let extension = AbiExtension::new(event_extension);
+        event_extension.extend_event_content_hash();
+        let extension = match abi {
+            "tests" => {
+                event_extension.extend_event_merge("EventAddressIdxString");
+                AbiExtension::new(event_extension)
+            }
+            "erc20" => {
+                let mut extension = AbiExtension::new(event_extension);
+                extension.extend_activity();
+                extension
+            }
+            _ => AbiExtension::new(event_extension),
+        };
         abigen.add_extension(extension).generate()?.write_to_file(&out_path)?;
     }
 
@@ -1,4 +1,8 @@
 #[rustfmt::skip]
 #[allow(dead_code)]
 #[allow(unused_variables)]
+pub mod erc20;
+#[rustfmt::skip]
+#[allow(dead_code)]
+#[allow(unused_variables)]
 pub mod tests;
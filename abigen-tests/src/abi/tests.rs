@@ -482,7 +482,11 @@ pub mod functions {
                     ethabi::Token::FixedBytes(self.param3.as_ref().to_vec()),
                     {
                         let non_full_signed_bytes = self.param4.to_signed_bytes_be();
-                        let mut full_signed_bytes = [0xff as u8; 32];
+                        let sign_byte = match non_full_signed_bytes.first() {
+                            Some(byte) if byte & 0x80 == 0x80 => 0xffu8,
+                            _ => 0x00u8,
+                        };
+                        let mut full_signed_bytes = [sign_byte; 32];
                         non_full_signed_bytes
                             .into_iter()
                             .rev()
@@ -660,7 +664,11 @@ pub mod functions {
                 &[
                     {
                         let non_full_signed_bytes = self.param0.to_signed_bytes_be();
-                        let mut full_signed_bytes = [0xff as u8; 32];
+                        let sign_byte = match non_full_signed_bytes.first() {
+                            Some(byte) if byte & 0x80 == 0x80 => 0xffu8,
+                            _ => 0x00u8,
+                        };
+                        let mut full_signed_bytes = [sign_byte; 32];
                         non_full_signed_bytes
                             .into_iter()
                             .rev()
@@ -735,7 +743,11 @@ pub mod functions {
                 &[
                     {
                         let non_full_signed_bytes = self.param0.to_signed_bytes_be();
-                        let mut full_signed_bytes = [0xff as u8; 32];
+                        let sign_byte = match non_full_signed_bytes.first() {
+                            Some(byte) if byte & 0x80 == 0x80 => 0xffu8,
+                            _ => 0x00u8,
+                        };
+                        let mut full_signed_bytes = [sign_byte; 32];
                         non_full_signed_bytes
                             .into_iter()
                             .rev()
@@ -810,7 +822,11 @@ pub mod functions {
                 &[
                     {
                         let non_full_signed_bytes = self.param0.to_signed_bytes_be();
-                        let mut full_signed_bytes = [0xff as u8; 32];
+                        let sign_byte = match non_full_signed_bytes.first() {
+                            Some(byte) if byte & 0x80 == 0x80 => 0xffu8,
+                            _ => 0x00u8,
+                        };
+                        let mut full_signed_bytes = [sign_byte; 32];
                         non_full_signed_bytes
                             .into_iter()
                             .rev()
@@ -923,7 +939,11 @@ pub mod functions {
                 &[
                     {
                         let non_full_signed_bytes = self.param0.to_signed_bytes_be();
-                        let mut full_signed_bytes = [0xff as u8; 32];
+                        let sign_byte = match non_full_signed_bytes.first() {
+                            Some(byte) if byte & 0x80 == 0x80 => 0xffu8,
+                            _ => 0x00u8,
+                        };
+                        let mut full_signed_bytes = [sign_byte; 32];
                         non_full_signed_bytes
                             .into_iter()
                             .rev()
@@ -935,7 +955,11 @@ pub mod functions {
                     },
                     {
                         let non_full_signed_bytes = self.param1.to_signed_bytes_be();
-                        let mut full_signed_bytes = [0xff as u8; 32];
+                        let sign_byte = match non_full_signed_bytes.first() {
+                            Some(byte) if byte & 0x80 == 0x80 => 0xffu8,
+                            _ => 0x00u8,
+                        };
+                        let mut full_signed_bytes = [sign_byte; 32];
                         non_full_signed_bytes
                             .into_iter()
                             .rev()
@@ -947,7 +971,11 @@ pub mod functions {
                     },
                     {
                         let non_full_signed_bytes = self.param2.to_signed_bytes_be();
-                        let mut full_signed_bytes = [0xff as u8; 32];
+                        let sign_byte = match non_full_signed_bytes.first() {
+                            Some(byte) if byte & 0x80 == 0x80 => 0xffu8,
+                            _ => 0x00u8,
+                        };
+                        let mut full_signed_bytes = [sign_byte; 32];
                         non_full_signed_bytes
                             .into_iter()
                             .rev()
@@ -959,7 +987,11 @@ pub mod functions {
                     },
                     {
                         let non_full_signed_bytes = self.param3.to_signed_bytes_be();
-                        let mut full_signed_bytes = [0xff as u8; 32];
+                        let sign_byte = match non_full_signed_bytes.first() {
+                            Some(byte) if byte & 0x80 == 0x80 => 0xffu8,
+                            _ => 0x00u8,
+                        };
+                        let mut full_signed_bytes = [sign_byte; 32];
                         non_full_signed_bytes
                             .into_iter()
                             .rev()
@@ -1514,6 +1546,7 @@ pub mod functions {
 #[allow(dead_code, unused_imports, unused_variables)]
 pub mod events {
     use super::INTERNAL_ERR;
+    #[derive(Debug, Clone, PartialEq)]
     pub enum Events {
         EventAddressIdxString(EventAddressIdxString),
         EventAddressIdxStringUint256IdxBytes(EventAddressIdxStringUint256IdxBytes),
@@ -1635,6 +1668,7 @@ pub mod events {
             70u8,
             168u8,
         ];
+        pub const FIELD_SOL_TYPES: &'static [&'static str] = &["address", "string"];
         pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
             if log.topics.len() != 2usize {
                 return false;
@@ -1678,6 +1712,30 @@ pub mod events {
                     .expect(INTERNAL_ERR),
             })
         }
+        pub fn to_log(&self) -> substreams_ethereum::pb::eth::v2::Log {
+            substreams_ethereum::pb::eth::v2::Log {
+                topics: vec![
+                    Self::TOPIC_ID.to_vec(),
+                    ethabi::encode(
+                        &[ethabi::Token::Address(ethabi::Address::from_slice(&self.first))],
+                    ),
+                ],
+                data: ethabi::encode(
+                    &[
+                        ethabi::Token::String(self.second.clone()),
+                    ],
+                ),
+                ..Default::default()
+            }
+        }
+        pub fn content_hash(&self) -> [u8; 32] {
+            substreams_ethereum::log_content_hash(&self.to_log())
+        }
+        pub fn match_and_decode_merged<L: AsRef<substreams_ethereum::pb::eth::v2::Log>>(
+            logs: impl IntoIterator<Item = L>,
+        ) -> Vec<Self> {
+            substreams_ethereum::merge_consecutive(logs)
+        }
     }
     impl substreams_ethereum::Event for EventAddressIdxString {
         const NAME: &'static str = "EventAddressIdxString";
@@ -1688,6 +1746,10 @@ pub mod events {
             Self::decode(log)
         }
     }
+    impl substreams_ethereum::EventSignature for EventAddressIdxString {
+        const TOPIC_ID: [u8; 32] = Self::TOPIC_ID;
+        const ANONYMOUS: bool = false;
+    }
     #[derive(Debug, Clone, PartialEq)]
     pub struct EventAddressIdxStringUint256IdxBytes {
         pub first: Vec<u8>,
@@ -1730,6 +1792,12 @@ pub mod events {
             51u8,
             169u8,
         ];
+        pub const FIELD_SOL_TYPES: &'static [&'static str] = &[
+            "address",
+            "string",
+            "uint256",
+            "bytes",
+        ];
         pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
             if log.topics.len() != 3usize {
                 return false;
@@ -1797,6 +1865,42 @@ pub mod events {
                     .expect(INTERNAL_ERR),
             })
         }
+        pub fn to_log(&self) -> substreams_ethereum::pb::eth::v2::Log {
+            substreams_ethereum::pb::eth::v2::Log {
+                topics: vec![
+                    Self::TOPIC_ID.to_vec(),
+                    ethabi::encode(
+                        &[ethabi::Token::Address(ethabi::Address::from_slice(&self.first))],
+                    ),
+                    ethabi::encode(
+                        &[
+                            ethabi::Token::Uint(
+                                ethabi::Uint::from_big_endian(
+                                    match self.third.clone().to_bytes_be() {
+                                        (num_bigint::Sign::Plus, bytes) => bytes,
+                                        (num_bigint::Sign::NoSign, bytes) => bytes,
+                                        (num_bigint::Sign::Minus, _) => {
+                                            panic!("negative numbers are not supported")
+                                        }
+                                    }
+                                        .as_slice(),
+                                ),
+                            ),
+                        ],
+                    ),
+                ],
+                data: ethabi::encode(
+                    &[
+                        ethabi::Token::String(self.second.clone()),
+                        ethabi::Token::Bytes(self.fourth.clone()),
+                    ],
+                ),
+                ..Default::default()
+            }
+        }
+        pub fn content_hash(&self) -> [u8; 32] {
+            substreams_ethereum::log_content_hash(&self.to_log())
+        }
     }
     impl substreams_ethereum::Event for EventAddressIdxStringUint256IdxBytes {
         const NAME: &'static str = "EventAddressIdxStringUint256IdxBytes";
@@ -1807,6 +1911,10 @@ pub mod events {
             Self::decode(log)
         }
     }
+    impl substreams_ethereum::EventSignature for EventAddressIdxStringUint256IdxBytes {
+        const TOPIC_ID: [u8; 32] = Self::TOPIC_ID;
+        const ANONYMOUS: bool = false;
+    }
     #[derive(Debug, Clone, PartialEq)]
     pub struct EventAddressIdxUint256Uint256AddressIdx {
         pub first: Vec<u8>,
@@ -1849,6 +1957,12 @@ pub mod events {
             217u8,
             125u8,
         ];
+        pub const FIELD_SOL_TYPES: &'static [&'static str] = &[
+            "address",
+            "uint256",
+            "uint256",
+            "address",
+        ];
         pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
             if log.topics.len() != 3usize {
                 return false;
@@ -1926,6 +2040,51 @@ pub mod events {
                 },
             })
         }
+        pub fn to_log(&self) -> substreams_ethereum::pb::eth::v2::Log {
+            substreams_ethereum::pb::eth::v2::Log {
+                topics: vec![
+                    Self::TOPIC_ID.to_vec(),
+                    ethabi::encode(
+                        &[ethabi::Token::Address(ethabi::Address::from_slice(&self.first))],
+                    ),
+                    ethabi::encode(
+                        &[ethabi::Token::Address(ethabi::Address::from_slice(&self.fourth))],
+                    ),
+                ],
+                data: ethabi::encode(
+                    &[
+                        ethabi::Token::Uint(
+                            ethabi::Uint::from_big_endian(
+                                match self.second.clone().to_bytes_be() {
+                                    (num_bigint::Sign::Plus, bytes) => bytes,
+                                    (num_bigint::Sign::NoSign, bytes) => bytes,
+                                    (num_bigint::Sign::Minus, _) => {
+                                        panic!("negative numbers are not supported")
+                                    }
+                                }
+                                    .as_slice(),
+                            ),
+                        ),
+                        ethabi::Token::Uint(
+                            ethabi::Uint::from_big_endian(
+                                match self.third.clone().to_bytes_be() {
+                                    (num_bigint::Sign::Plus, bytes) => bytes,
+                                    (num_bigint::Sign::NoSign, bytes) => bytes,
+                                    (num_bigint::Sign::Minus, _) => {
+                                        panic!("negative numbers are not supported")
+                                    }
+                                }
+                                    .as_slice(),
+                            ),
+                        ),
+                    ],
+                ),
+                ..Default::default()
+            }
+        }
+        pub fn content_hash(&self) -> [u8; 32] {
+            substreams_ethereum::log_content_hash(&self.to_log())
+        }
     }
     impl substreams_ethereum::Event for EventAddressIdxUint256Uint256AddressIdx {
         const NAME: &'static str = "EventAddressIdxUint256Uint256AddressIdx";
@@ -1936,6 +2095,10 @@ pub mod events {
             Self::decode(log)
         }
     }
+    impl substreams_ethereum::EventSignature for EventAddressIdxUint256Uint256AddressIdx {
+        const TOPIC_ID: [u8; 32] = Self::TOPIC_ID;
+        const ANONYMOUS: bool = false;
+    }
     #[derive(Debug, Clone, PartialEq)]
     pub struct EventBytes20UintAddressIdx {
         pub first: [u8; 20usize],
@@ -1977,6 +2140,7 @@ pub mod events {
             147u8,
             225u8,
         ];
+        pub const FIELD_SOL_TYPES: &'static [&'static str] = &["bytes20", "uint256", "address"];
         pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
             if log.topics.len() != 2usize {
                 return false;
@@ -2038,6 +2202,37 @@ pub mod events {
                 },
             })
         }
+        pub fn to_log(&self) -> substreams_ethereum::pb::eth::v2::Log {
+            substreams_ethereum::pb::eth::v2::Log {
+                topics: vec![
+                    Self::TOPIC_ID.to_vec(),
+                    ethabi::encode(
+                        &[ethabi::Token::Address(ethabi::Address::from_slice(&self.third))],
+                    ),
+                ],
+                data: ethabi::encode(
+                    &[
+                        ethabi::Token::FixedBytes(self.first.as_ref().to_vec()),
+                        ethabi::Token::Uint(
+                            ethabi::Uint::from_big_endian(
+                                match self.second.clone().to_bytes_be() {
+                                    (num_bigint::Sign::Plus, bytes) => bytes,
+                                    (num_bigint::Sign::NoSign, bytes) => bytes,
+                                    (num_bigint::Sign::Minus, _) => {
+                                        panic!("negative numbers are not supported")
+                                    }
+                                }
+                                    .as_slice(),
+                            ),
+                        ),
+                    ],
+                ),
+                ..Default::default()
+            }
+        }
+        pub fn content_hash(&self) -> [u8; 32] {
+            substreams_ethereum::log_content_hash(&self.to_log())
+        }
     }
     impl substreams_ethereum::Event for EventBytes20UintAddressIdx {
         const NAME: &'static str = "EventBytes20UintAddressIdx";
@@ -2048,6 +2243,10 @@ pub mod events {
             Self::decode(log)
         }
     }
+    impl substreams_ethereum::EventSignature for EventBytes20UintAddressIdx {
+        const TOPIC_ID: [u8; 32] = Self::TOPIC_ID;
+        const ANONYMOUS: bool = false;
+    }
     #[derive(Debug, Clone, PartialEq)]
     pub struct EventBytes32UintAddressIdx {
         pub first: [u8; 32usize],
@@ -2089,6 +2288,7 @@ pub mod events {
             176u8,
             179u8,
         ];
+        pub const FIELD_SOL_TYPES: &'static [&'static str] = &["bytes32", "uint256", "address"];
         pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
             if log.topics.len() != 2usize {
                 return false;
@@ -2150,6 +2350,37 @@ pub mod events {
                 },
             })
         }
+        pub fn to_log(&self) -> substreams_ethereum::pb::eth::v2::Log {
+            substreams_ethereum::pb::eth::v2::Log {
+                topics: vec![
+                    Self::TOPIC_ID.to_vec(),
+                    ethabi::encode(
+                        &[ethabi::Token::Address(ethabi::Address::from_slice(&self.third))],
+                    ),
+                ],
+                data: ethabi::encode(
+                    &[
+                        ethabi::Token::FixedBytes(self.first.as_ref().to_vec()),
+                        ethabi::Token::Uint(
+                            ethabi::Uint::from_big_endian(
+                                match self.second.clone().to_bytes_be() {
+                                    (num_bigint::Sign::Plus, bytes) => bytes,
+                                    (num_bigint::Sign::NoSign, bytes) => bytes,
+                                    (num_bigint::Sign::Minus, _) => {
+                                        panic!("negative numbers are not supported")
+                                    }
+                                }
+                                    .as_slice(),
+                            ),
+                        ),
+                    ],
+                ),
+                ..Default::default()
+            }
+        }
+        pub fn content_hash(&self) -> [u8; 32] {
+            substreams_ethereum::log_content_hash(&self.to_log())
+        }
     }
     impl substreams_ethereum::Event for EventBytes32UintAddressIdx {
         const NAME: &'static str = "EventBytes32UintAddressIdx";
@@ -2160,6 +2391,10 @@ pub mod events {
             Self::decode(log)
         }
     }
+    impl substreams_ethereum::EventSignature for EventBytes32UintAddressIdx {
+        const TOPIC_ID: [u8; 32] = Self::TOPIC_ID;
+        const ANONYMOUS: bool = false;
+    }
     #[derive(Debug, Clone, PartialEq)]
     pub struct EventInt256 {
         pub param0: substreams::scalar::BigInt,
@@ -2199,6 +2434,7 @@ pub mod events {
             237u8,
             69u8,
         ];
+        pub const FIELD_SOL_TYPES: &'static [&'static str] = &["int256"];
         pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
             if log.topics.len() != 1usize {
                 return false;
@@ -2231,6 +2467,35 @@ pub mod events {
                 },
             })
         }
+        pub fn to_log(&self) -> substreams_ethereum::pb::eth::v2::Log {
+            substreams_ethereum::pb::eth::v2::Log {
+                topics: vec![Self::TOPIC_ID.to_vec()],
+                data: ethabi::encode(
+                    &[
+                        {
+                            let non_full_signed_bytes = self.param0.to_signed_bytes_be();
+                            let sign_byte = match non_full_signed_bytes.first() {
+                                Some(byte) if byte & 0x80 == 0x80 => 0xffu8,
+                                _ => 0x00u8,
+                            };
+                            let mut full_signed_bytes = [sign_byte; 32];
+                            non_full_signed_bytes
+                                .into_iter()
+                                .rev()
+                                .enumerate()
+                                .for_each(|(i, byte)| full_signed_bytes[31 - i] = byte);
+                            ethabi::Token::Int(
+                                ethabi::Int::from_big_endian(full_signed_bytes.as_ref()),
+                            )
+                        },
+                    ],
+                ),
+                ..Default::default()
+            }
+        }
+        pub fn content_hash(&self) -> [u8; 32] {
+            substreams_ethereum::log_content_hash(&self.to_log())
+        }
     }
     impl substreams_ethereum::Event for EventInt256 {
         const NAME: &'static str = "EventInt256";
@@ -2241,6 +2506,10 @@ pub mod events {
             Self::decode(log)
         }
     }
+    impl substreams_ethereum::EventSignature for EventInt256 {
+        const TOPIC_ID: [u8; 32] = Self::TOPIC_ID;
+        const ANONYMOUS: bool = false;
+    }
     #[derive(Debug, Clone, PartialEq)]
     pub struct EventInt256Idx {
         pub param0: substreams::scalar::BigInt,
@@ -2280,6 +2549,7 @@ pub mod events {
             71u8,
             39u8,
         ];
+        pub const FIELD_SOL_TYPES: &'static [&'static str] = &["int256"];
         pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
             if log.topics.len() != 2usize {
                 return false;
@@ -2299,6 +2569,38 @@ pub mod events {
                 ),
             })
         }
+        pub fn to_log(&self) -> substreams_ethereum::pb::eth::v2::Log {
+            substreams_ethereum::pb::eth::v2::Log {
+                topics: vec![
+                    Self::TOPIC_ID.to_vec(),
+                    ethabi::encode(
+                        &[
+                            {
+                                let non_full_signed_bytes = self.param0.to_signed_bytes_be();
+                                let sign_byte = match non_full_signed_bytes.first() {
+                                    Some(byte) if byte & 0x80 == 0x80 => 0xffu8,
+                                    _ => 0x00u8,
+                                };
+                                let mut full_signed_bytes = [sign_byte; 32];
+                                non_full_signed_bytes
+                                    .into_iter()
+                                    .rev()
+                                    .enumerate()
+                                    .for_each(|(i, byte)| full_signed_bytes[31 - i] = byte);
+                                ethabi::Token::Int(
+                                    ethabi::Int::from_big_endian(full_signed_bytes.as_ref()),
+                                )
+                            },
+                        ],
+                    ),
+                ],
+                data: ethabi::encode(&[]),
+                ..Default::default()
+            }
+        }
+        pub fn content_hash(&self) -> [u8; 32] {
+            substreams_ethereum::log_content_hash(&self.to_log())
+        }
     }
     impl substreams_ethereum::Event for EventInt256Idx {
         const NAME: &'static str = "EventInt256Idx";
@@ -2309,6 +2611,10 @@ pub mod events {
             Self::decode(log)
         }
     }
+    impl substreams_ethereum::EventSignature for EventInt256Idx {
+        const TOPIC_ID: [u8; 32] = Self::TOPIC_ID;
+        const ANONYMOUS: bool = false;
+    }
     #[derive(Debug, Clone, PartialEq)]
     pub struct EventUArrayBool {
         pub param0: Vec<bool>,
@@ -2348,6 +2654,7 @@ pub mod events {
             175u8,
             182u8,
         ];
+        pub const FIELD_SOL_TYPES: &'static [&'static str] = &["bool[]"];
         pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
             if log.topics.len() != 1usize {
                 return false;
@@ -2378,6 +2685,27 @@ pub mod events {
                     .collect(),
             })
         }
+        pub fn to_log(&self) -> substreams_ethereum::pb::eth::v2::Log {
+            substreams_ethereum::pb::eth::v2::Log {
+                topics: vec![Self::TOPIC_ID.to_vec()],
+                data: ethabi::encode(
+                    &[
+                        {
+                            let v = self
+                                .param0
+                                .iter()
+                                .map(|inner| ethabi::Token::Bool(inner.clone()))
+                                .collect();
+                            ethabi::Token::Array(v)
+                        },
+                    ],
+                ),
+                ..Default::default()
+            }
+        }
+        pub fn content_hash(&self) -> [u8; 32] {
+            substreams_ethereum::log_content_hash(&self.to_log())
+        }
     }
     impl substreams_ethereum::Event for EventUArrayBool {
         const NAME: &'static str = "EventUArrayBool";
@@ -2388,6 +2716,10 @@ pub mod events {
             Self::decode(log)
         }
     }
+    impl substreams_ethereum::EventSignature for EventUArrayBool {
+        const TOPIC_ID: [u8; 32] = Self::TOPIC_ID;
+        const ANONYMOUS: bool = false;
+    }
     #[derive(Debug, Clone, PartialEq)]
     pub struct EventUBytes8UBytes16UBytes24UBytes32 {
         pub param0: [u8; 8usize],
@@ -2430,6 +2762,12 @@ pub mod events {
             176u8,
             196u8,
         ];
+        pub const FIELD_SOL_TYPES: &'static [&'static str] = &[
+            "bytes8",
+            "bytes16",
+            "bytes24",
+            "bytes32",
+        ];
         pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
             if log.topics.len() != 1usize {
                 return false;
@@ -2497,6 +2835,23 @@ pub mod events {
                 },
             })
         }
+        pub fn to_log(&self) -> substreams_ethereum::pb::eth::v2::Log {
+            substreams_ethereum::pb::eth::v2::Log {
+                topics: vec![Self::TOPIC_ID.to_vec()],
+                data: ethabi::encode(
+                    &[
+                        ethabi::Token::FixedBytes(self.param0.as_ref().to_vec()),
+                        ethabi::Token::FixedBytes(self.param1.as_ref().to_vec()),
+                        ethabi::Token::FixedBytes(self.param2.as_ref().to_vec()),
+                        ethabi::Token::FixedBytes(self.param3.as_ref().to_vec()),
+                    ],
+                ),
+                ..Default::default()
+            }
+        }
+        pub fn content_hash(&self) -> [u8; 32] {
+            substreams_ethereum::log_content_hash(&self.to_log())
+        }
     }
     impl substreams_ethereum::Event for EventUBytes8UBytes16UBytes24UBytes32 {
         const NAME: &'static str = "EventUBytes8UBytes16UBytes24UBytes32";
@@ -2507,6 +2862,10 @@ pub mod events {
             Self::decode(log)
         }
     }
+    impl substreams_ethereum::EventSignature for EventUBytes8UBytes16UBytes24UBytes32 {
+        const TOPIC_ID: [u8; 32] = Self::TOPIC_ID;
+        const ANONYMOUS: bool = false;
+    }
     #[derive(Debug, Clone, PartialEq)]
     pub struct EventUFixedArrayString {
         pub param0: [String; 2usize],
@@ -2546,6 +2905,7 @@ pub mod events {
             19u8,
             124u8,
         ];
+        pub const FIELD_SOL_TYPES: &'static [&'static str] = &["string[2]"];
         pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
             if log.topics.len() != 1usize {
                 return false;
@@ -2583,6 +2943,27 @@ pub mod events {
                 },
             })
         }
+        pub fn to_log(&self) -> substreams_ethereum::pb::eth::v2::Log {
+            substreams_ethereum::pb::eth::v2::Log {
+                topics: vec![Self::TOPIC_ID.to_vec()],
+                data: ethabi::encode(
+                    &[
+                        {
+                            let v = self
+                                .param0
+                                .iter()
+                                .map(|inner| ethabi::Token::String(inner.clone()))
+                                .collect();
+                            ethabi::Token::FixedArray(v)
+                        },
+                    ],
+                ),
+                ..Default::default()
+            }
+        }
+        pub fn content_hash(&self) -> [u8; 32] {
+            substreams_ethereum::log_content_hash(&self.to_log())
+        }
     }
     impl substreams_ethereum::Event for EventUFixedArrayString {
         const NAME: &'static str = "EventUFixedArrayString";
@@ -2593,6 +2974,10 @@ pub mod events {
             Self::decode(log)
         }
     }
+    impl substreams_ethereum::EventSignature for EventUFixedArrayString {
+        const TOPIC_ID: [u8; 32] = Self::TOPIC_ID;
+        const ANONYMOUS: bool = false;
+    }
     #[derive(Debug, Clone, PartialEq)]
     pub struct EventUFixedArraySubDynamic {
         pub param0: [Vec<u8>; 2usize],
@@ -2632,6 +3017,7 @@ pub mod events {
             140u8,
             216u8,
         ];
+        pub const FIELD_SOL_TYPES: &'static [&'static str] = &["bytes[2]"];
         pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
             if log.topics.len() != 1usize {
                 return false;
@@ -2669,6 +3055,27 @@ pub mod events {
                 },
             })
         }
+        pub fn to_log(&self) -> substreams_ethereum::pb::eth::v2::Log {
+            substreams_ethereum::pb::eth::v2::Log {
+                topics: vec![Self::TOPIC_ID.to_vec()],
+                data: ethabi::encode(
+                    &[
+                        {
+                            let v = self
+                                .param0
+                                .iter()
+                                .map(|inner| ethabi::Token::Bytes(inner.clone()))
+                                .collect();
+                            ethabi::Token::FixedArray(v)
+                        },
+                    ],
+                ),
+                ..Default::default()
+            }
+        }
+        pub fn content_hash(&self) -> [u8; 32] {
+            substreams_ethereum::log_content_hash(&self.to_log())
+        }
     }
     impl substreams_ethereum::Event for EventUFixedArraySubDynamic {
         const NAME: &'static str = "EventUFixedArraySubDynamic";
@@ -2679,6 +3086,10 @@ pub mod events {
             Self::decode(log)
         }
     }
+    impl substreams_ethereum::EventSignature for EventUFixedArraySubDynamic {
+        const TOPIC_ID: [u8; 32] = Self::TOPIC_ID;
+        const ANONYMOUS: bool = false;
+    }
     #[derive(Debug, Clone, PartialEq)]
     pub struct EventUFixedArraySubFixed {
         pub param0: [Vec<u8>; 2usize],
@@ -2718,6 +3129,7 @@ pub mod events {
             171u8,
             178u8,
         ];
+        pub const FIELD_SOL_TYPES: &'static [&'static str] = &["address[2]"];
         pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
             if log.topics.len() != 1usize {
                 return false;
@@ -2757,6 +3169,29 @@ pub mod events {
                 },
             })
         }
+        pub fn to_log(&self) -> substreams_ethereum::pb::eth::v2::Log {
+            substreams_ethereum::pb::eth::v2::Log {
+                topics: vec![Self::TOPIC_ID.to_vec()],
+                data: ethabi::encode(
+                    &[
+                        {
+                            let v = self
+                                .param0
+                                .iter()
+                                .map(|inner| ethabi::Token::Address(
+                                    ethabi::Address::from_slice(&inner),
+                                ))
+                                .collect();
+                            ethabi::Token::FixedArray(v)
+                        },
+                    ],
+                ),
+                ..Default::default()
+            }
+        }
+        pub fn content_hash(&self) -> [u8; 32] {
+            substreams_ethereum::log_content_hash(&self.to_log())
+        }
     }
     impl substreams_ethereum::Event for EventUFixedArraySubFixed {
         const NAME: &'static str = "EventUFixedArraySubFixed";
@@ -2767,6 +3202,10 @@ pub mod events {
             Self::decode(log)
         }
     }
+    impl substreams_ethereum::EventSignature for EventUFixedArraySubFixed {
+        const TOPIC_ID: [u8; 32] = Self::TOPIC_ID;
+        const ANONYMOUS: bool = false;
+    }
     #[derive(Debug, Clone, PartialEq)]
     pub struct EventUTupleAddress {
         pub param0: (Vec<u8>,),
@@ -2806,6 +3245,7 @@ pub mod events {
             135u8,
             211u8,
         ];
+        pub const FIELD_SOL_TYPES: &'static [&'static str] = &["(address)"];
         pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
             if log.topics.len() != 1usize {
                 return false;
@@ -2843,6 +3283,24 @@ pub mod events {
                 },
             })
         }
+        pub fn to_log(&self) -> substreams_ethereum::pb::eth::v2::Log {
+            substreams_ethereum::pb::eth::v2::Log {
+                topics: vec![Self::TOPIC_ID.to_vec()],
+                data: ethabi::encode(
+                    &[
+                        ethabi::Token::Tuple(
+                            vec![
+                                ethabi::Token::Address(ethabi::Address::from_slice(&self.param0.0)),
+                            ],
+                        ),
+                    ],
+                ),
+                ..Default::default()
+            }
+        }
+        pub fn content_hash(&self) -> [u8; 32] {
+            substreams_ethereum::log_content_hash(&self.to_log())
+        }
     }
     impl substreams_ethereum::Event for EventUTupleAddress {
         const NAME: &'static str = "EventUTupleAddress";
@@ -2853,6 +3311,10 @@ pub mod events {
             Self::decode(log)
         }
     }
+    impl substreams_ethereum::EventSignature for EventUTupleAddress {
+        const TOPIC_ID: [u8; 32] = Self::TOPIC_ID;
+        const ANONYMOUS: bool = false;
+    }
     #[derive(Debug, Clone, PartialEq)]
     pub struct EventUTupleBool {
         pub param0: (bool,),
@@ -2892,6 +3354,7 @@ pub mod events {
             255u8,
             35u8,
         ];
+        pub const FIELD_SOL_TYPES: &'static [&'static str] = &["(bool)"];
         pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
             if log.topics.len() != 1usize {
                 return false;
@@ -2922,6 +3385,24 @@ pub mod events {
                 },
             })
         }
+        pub fn to_log(&self) -> substreams_ethereum::pb::eth::v2::Log {
+            substreams_ethereum::pb::eth::v2::Log {
+                topics: vec![Self::TOPIC_ID.to_vec()],
+                data: ethabi::encode(
+                    &[
+                        ethabi::Token::Tuple(
+                            vec![
+                                ethabi::Token::Bool(self.param0.0.clone()),
+                            ],
+                        ),
+                    ],
+                ),
+                ..Default::default()
+            }
+        }
+        pub fn content_hash(&self) -> [u8; 32] {
+            substreams_ethereum::log_content_hash(&self.to_log())
+        }
     }
     impl substreams_ethereum::Event for EventUTupleBool {
         const NAME: &'static str = "EventUTupleBool";
@@ -2932,6 +3413,10 @@ pub mod events {
             Self::decode(log)
         }
     }
+    impl substreams_ethereum::EventSignature for EventUTupleBool {
+        const TOPIC_ID: [u8; 32] = Self::TOPIC_ID;
+        const ANONYMOUS: bool = false;
+    }
     #[derive(Debug, Clone, PartialEq)]
     pub struct EventWithOverloads1 {
         pub first: Vec<u8>,
@@ -2971,6 +3456,7 @@ pub mod events {
             28u8,
             21u8,
         ];
+        pub const FIELD_SOL_TYPES: &'static [&'static str] = &["address"];
         pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
             if log.topics.len() != 2usize {
                 return false;
@@ -3003,6 +3489,21 @@ pub mod events {
                     .to_vec(),
             })
         }
+        pub fn to_log(&self) -> substreams_ethereum::pb::eth::v2::Log {
+            substreams_ethereum::pb::eth::v2::Log {
+                topics: vec![
+                    Self::TOPIC_ID.to_vec(),
+                    ethabi::encode(
+                        &[ethabi::Token::Address(ethabi::Address::from_slice(&self.first))],
+                    ),
+                ],
+                data: ethabi::encode(&[]),
+                ..Default::default()
+            }
+        }
+        pub fn content_hash(&self) -> [u8; 32] {
+            substreams_ethereum::log_content_hash(&self.to_log())
+        }
     }
     impl substreams_ethereum::Event for EventWithOverloads1 {
         const NAME: &'static str = "EventWithOverloads1";
@@ -3013,6 +3514,10 @@ pub mod events {
             Self::decode(log)
         }
     }
+    impl substreams_ethereum::EventSignature for EventWithOverloads1 {
+        const TOPIC_ID: [u8; 32] = Self::TOPIC_ID;
+        const ANONYMOUS: bool = false;
+    }
     #[derive(Debug, Clone, PartialEq)]
     pub struct EventWithOverloads2 {
         pub second: String,
@@ -3052,6 +3557,7 @@ pub mod events {
             35u8,
             150u8,
         ];
+        pub const FIELD_SOL_TYPES: &'static [&'static str] = &["string"];
         pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
             if log.topics.len() != 2usize {
                 return false;
@@ -3092,6 +3598,10 @@ pub mod events {
             Self::decode(log)
         }
     }
+    impl substreams_ethereum::EventSignature for EventWithOverloads2 {
+        const TOPIC_ID: [u8; 32] = Self::TOPIC_ID;
+        const ANONYMOUS: bool = false;
+    }
     #[derive(Debug, Clone, PartialEq)]
     pub struct EventWithOverloads3 {
         pub third: substreams::scalar::BigInt,
@@ -3131,6 +3641,7 @@ pub mod events {
             73u8,
             118u8,
         ];
+        pub const FIELD_SOL_TYPES: &'static [&'static str] = &["uint256"];
         pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
             if log.topics.len() != 2usize {
                 return false;
@@ -3166,6 +3677,34 @@ pub mod events {
                 },
             })
         }
+        pub fn to_log(&self) -> substreams_ethereum::pb::eth::v2::Log {
+            substreams_ethereum::pb::eth::v2::Log {
+                topics: vec![
+                    Self::TOPIC_ID.to_vec(),
+                    ethabi::encode(
+                        &[
+                            ethabi::Token::Uint(
+                                ethabi::Uint::from_big_endian(
+                                    match self.third.clone().to_bytes_be() {
+                                        (num_bigint::Sign::Plus, bytes) => bytes,
+                                        (num_bigint::Sign::NoSign, bytes) => bytes,
+                                        (num_bigint::Sign::Minus, _) => {
+                                            panic!("negative numbers are not supported")
+                                        }
+                                    }
+                                        .as_slice(),
+                                ),
+                            ),
+                        ],
+                    ),
+                ],
+                data: ethabi::encode(&[]),
+                ..Default::default()
+            }
+        }
+        pub fn content_hash(&self) -> [u8; 32] {
+            substreams_ethereum::log_content_hash(&self.to_log())
+        }
     }
     impl substreams_ethereum::Event for EventWithOverloads3 {
         const NAME: &'static str = "EventWithOverloads3";
@@ -3176,4 +3715,8 @@ pub mod events {
             Self::decode(log)
         }
     }
+    impl substreams_ethereum::EventSignature for EventWithOverloads3 {
+        const TOPIC_ID: [u8; 32] = Self::TOPIC_ID;
+        const ANONYMOUS: bool = false;
+    }
 }
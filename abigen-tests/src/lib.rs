@@ -1,10 +1,22 @@
 mod abi;
 
+// Emitted strings for the same `first` address are chunks of a single string when logged
+// one after the other.
+impl substreams_ethereum::MergeEvent for abi::tests::events::EventAddressIdxString {
+    fn should_merge(&self, next: &Self) -> bool {
+        self.first == next.first
+    }
+
+    fn merge(&mut self, next: Self) {
+        self.second.push_str(&next.second);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
 
-    use crate::abi::tests;
+    use crate::abi::{erc20, tests};
     use pretty_assertions::assert_eq;
     use substreams::scalar::BigInt;
     use substreams::{hex, Hex};
@@ -258,6 +270,340 @@ mod tests {
         );
     }
 
+    #[test]
+    fn it_decode_event_address_idx_string_merged() {
+        use tests::events::EventAddressIdxString as Event;
+
+        let log = |first: &[u8], data: &[u8]| pb::eth::v2::Log {
+            address: hex!("0000000000000000000000000000000000000000").to_vec(),
+            topics: vec![
+                hex!("3cdb310171efa4c08617535044016fb81ec0a9db468c06b008d2f446ee9346a8").to_vec(),
+                [vec![0u8; 12], first.to_vec()].concat(),
+            ],
+            data: data.to_vec(),
+            ..Default::default()
+        };
+
+        let first = hex!("ab07a50ad459b41fe065f7bbab866d5390e9f705");
+        let other = hex!("cd91a50ad459b41fe065f7bbab866d5390e945fa");
+        let hello = hex!("0000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000000668656c6c6f200000000000000000000000000000000000000000000000000000");
+        let world = hex!("00000000000000000000000000000000000000000000000000000000000000200000000000000000000000000000000000000000000000000000000000000005776f726c64000000000000000000000000000000000000000000000000000000");
+        let bang = hex!("000000000000000000000000000000000000000000000000000000000000002000000000000000000000000000000000000000000000000000000000000000012100000000000000000000000000000000000000000000000000000000000000");
+
+        let unrelated = pb::eth::v2::Log {
+            address: hex!("0000000000000000000000000000000000000000").to_vec(),
+            topics: vec![
+                hex!("084d6aa2a24841fba4be2c27f3be03e19c312265fd3e6a73e92ce58c202a4727").to_vec(),
+                hex!("fffffffffffffffffffffffffffffffffffffffffffffffffffff713f526b11d").to_vec(),
+            ],
+            ..Default::default()
+        };
+
+        let logs = vec![
+            log(&first, &hello),
+            log(&first, &world),
+            log(&other, &bang),
+            log(&first, &bang),
+            unrelated,
+            log(&first, &world),
+            pb::eth::v2::Log {
+                address: hex!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48").to_vec(),
+                ..log(&first, &bang)
+            },
+        ];
+
+        assert_eq!(
+            Event::match_and_decode_merged(&logs),
+            vec![
+                Event {
+                    first: first.to_vec(),
+                    second: "hello world".to_string(),
+                },
+                Event {
+                    first: other.to_vec(),
+                    second: "!".to_string(),
+                },
+                Event {
+                    first: first.to_vec(),
+                    second: "!".to_string(),
+                },
+                Event {
+                    first: first.to_vec(),
+                    second: "world".to_string(),
+                },
+                Event {
+                    first: first.to_vec(),
+                    second: "!".to_string(),
+                },
+            ],
+        );
+    }
+
+    #[test]
+    fn it_decode_mixed_logs_with_registry() {
+        use substreams_ethereum::EventRegistry;
+
+        let mut registry = EventRegistry::new();
+        registry
+            .register::<erc20::events::Transfer>()
+            .register::<erc20::events::Approval>()
+            .register::<tests::events::EventInt256Idx>();
+
+        let transfer = pb::eth::v2::Log {
+            topics: vec![
+                hex!("ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef").to_vec(),
+                hex!("000000000000000000000000ab07a50ad459b41fe065f7bbab866d5390e9f705").to_vec(),
+                hex!("000000000000000000000000cd91a50ad459b41fe065f7bbab866d5390e945fa").to_vec(),
+            ],
+            data: hex!("0000000000000000000000000000000000000000000000000de0b6b3a7640000").to_vec(),
+            ..Default::default()
+        };
+
+        let int256_idx = pb::eth::v2::Log {
+            topics: vec![
+                hex!("084d6aa2a24841fba4be2c27f3be03e19c312265fd3e6a73e92ce58c202a4727").to_vec(),
+                hex!("fffffffffffffffffffffffffffffffffffffffffffffffffffff713f526b11d").to_vec(),
+            ],
+            ..Default::default()
+        };
+
+        let approval = pb::eth::v2::Log {
+            topics: vec![
+                hex!("8c5be1e5ebec7d5bd14f71427d1e84f3dd0314c0f7b2291e5b200ac8c7c3b925").to_vec(),
+                hex!("000000000000000000000000cd91a50ad459b41fe065f7bbab866d5390e945fa").to_vec(),
+                hex!("000000000000000000000000ab07a50ad459b41fe065f7bbab866d5390e9f705").to_vec(),
+            ],
+            data: hex!("0000000000000000000000000000000000000000000000000000000000000001").to_vec(),
+            ..Default::default()
+        };
+
+        let unregistered = pb::eth::v2::Log {
+            topics: vec![
+                hex!("e46e0615228a85d593cefeae9bb5f9d1b6698858b635d549b40492afb258ff23").to_vec(),
+            ],
+            data: hex!("0000000000000000000000000000000000000000000000000000000000000001").to_vec(),
+            ..Default::default()
+        };
+
+        let event = registry.decode(&transfer).expect("transfer is registered");
+        assert_eq!(event.name(), "Transfer");
+        assert_eq!(
+            event.downcast_ref::<erc20::events::Transfer>(),
+            Some(&erc20::events::Transfer {
+                from: hex!("ab07a50ad459b41fe065f7bbab866d5390e9f705").to_vec(),
+                to: hex!("cd91a50ad459b41fe065f7bbab866d5390e945fa").to_vec(),
+                value: BigInt::from(1_000_000_000_000_000_000u64),
+            }),
+        );
+
+        let event = registry.decode(&int256_idx).expect("int256_idx is registered");
+        assert_eq!(event.name(), "EventInt256Idx");
+        assert_eq!(
+            event.downcast_ref::<tests::events::EventInt256Idx>(),
+            Some(&tests::events::EventInt256Idx {
+                param0: BigInt::from(num_bigint::ToBigInt::to_bigint(&-9809887317731i64).unwrap()),
+            }),
+        );
+
+        let event = registry.decode(&approval).expect("approval is registered");
+        assert_eq!(event.is::<erc20::events::Approval>(), true);
+        assert_eq!(event.is::<erc20::events::Transfer>(), false);
+
+        assert_eq!(registry.decode(&unregistered).is_none(), true);
+    }
+
+    #[test]
+    fn it_event_content_hash() {
+        use erc20::events::Transfer as Event;
+
+        let log = pb::eth::v2::Log {
+            address: hex!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48").to_vec(),
+            topics: vec![
+                hex!("ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef").to_vec(),
+                hex!("000000000000000000000000ab07a50ad459b41fe065f7bbab866d5390e9f705").to_vec(),
+                hex!("000000000000000000000000cd91a50ad459b41fe065f7bbab866d5390e945fa").to_vec(),
+            ],
+            data: hex!("0000000000000000000000000000000000000000000000000de0b6b3a7640000").to_vec(),
+            index: 3,
+            ordinal: 12,
+            ..Default::default()
+        };
+
+        let first = Event::decode(&log).unwrap();
+        let second = Event::decode(&log).unwrap();
+
+        let re_encoded = first.to_log();
+        assert_eq!(re_encoded.topics, log.topics);
+        assert_eq!(re_encoded.data, log.data);
+
+        assert_eq!(first.content_hash(), second.content_hash());
+        assert_eq!(
+            first.content_hash(),
+            substreams_ethereum::log_content_hash(&log)
+        );
+
+        let other = Event {
+            value: BigInt::from(1u64),
+            ..first.clone()
+        };
+        assert_ne!(first.content_hash(), other.content_hash());
+    }
+
+    #[test]
+    fn it_event_int256_to_log() {
+        use tests::events::EventInt256 as Event;
+
+        let log = |data: [u8; 32]| pb::eth::v2::Log {
+            topics: vec![
+                hex!("a0bc7a55329cc29f990b7c48d9f4624e4c0c35eb955aee358f7b16441db9ed45").to_vec(),
+            ],
+            data: data.to_vec(),
+            ..Default::default()
+        };
+
+        for log in [
+            log(hex!("fffffffffffffffffffffffffffffffffffffffffffffffffffff713f526b11d")),
+            log(hex!("000000000000000000000000000000000000000000000000000008ec0ad94ee3")),
+            log(hex!("0000000000000000000000000000000000000000000000000000000000000080")),
+            log(hex!("0000000000000000000000000000000000000000000000000000000000000000")),
+        ] {
+            let re_encoded = Event::decode(&log).unwrap().to_log();
+            assert_eq!(re_encoded.topics, log.topics);
+            assert_eq!(re_encoded.data, log.data);
+        }
+    }
+
+    #[test]
+    fn it_event_int256_idx_to_log() {
+        use tests::events::EventInt256Idx as Event;
+
+        let log = |topic: [u8; 32]| pb::eth::v2::Log {
+            topics: vec![
+                hex!("084d6aa2a24841fba4be2c27f3be03e19c312265fd3e6a73e92ce58c202a4727").to_vec(),
+                topic.to_vec(),
+            ],
+            ..Default::default()
+        };
+
+        for log in [
+            log(hex!("fffffffffffffffffffffffffffffffffffffffffffffffffffff713f526b11d")),
+            log(hex!("000000000000000000000000000000000000000000000000000008ec0ad94ee3")),
+            log(hex!("0000000000000000000000000000000000000000000000000000000000000080")),
+            log(hex!("0000000000000000000000000000000000000000000000000000000000000000")),
+        ] {
+            let re_encoded = Event::decode(&log).unwrap().to_log();
+            assert_eq!(re_encoded.topics, log.topics);
+            assert_eq!(re_encoded.data, log.data);
+        }
+    }
+
+    #[test]
+    fn it_event_erc20_transfer_mint_burn() {
+        use erc20::events::Transfer as Event;
+
+        let log = |from: [u8; 20], to: [u8; 20]| pb::eth::v2::Log {
+            topics: vec![
+                hex!("ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef").to_vec(),
+                [vec![0u8; 12], from.to_vec()].concat(),
+                [vec![0u8; 12], to.to_vec()].concat(),
+            ],
+            data: hex!("0000000000000000000000000000000000000000000000000de0b6b3a7640000").to_vec(),
+            ..Default::default()
+        };
+
+        let holder = hex!("ab07a50ad459b41fe065f7bbab866d5390e9f705");
+        let other = hex!("cd91a50ad459b41fe065f7bbab866d5390e945fa");
+
+        let mint = Event::decode(&log(substreams_ethereum::NULL_ADDRESS, holder)).unwrap();
+        assert_eq!(mint.is_mint(), true);
+        assert_eq!(mint.is_burn(), false);
+
+        let burn = Event::decode(&log(holder, substreams_ethereum::NULL_ADDRESS)).unwrap();
+        assert_eq!(burn.is_mint(), false);
+        assert_eq!(burn.is_burn(), true);
+
+        let transfer = Event::decode(&log(holder, other)).unwrap();
+        assert_eq!(transfer.is_mint(), false);
+        assert_eq!(transfer.is_burn(), false);
+    }
+
+    #[test]
+    fn it_decode_event_with_ordinal() {
+        use erc20::events::Transfer as Event;
+        use substreams_ethereum::Event as _;
+
+        let log = |ordinal| pb::eth::v2::Log {
+            topics: vec![
+                hex!("ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef").to_vec(),
+                hex!("000000000000000000000000ab07a50ad459b41fe065f7bbab866d5390e9f705").to_vec(),
+                hex!("000000000000000000000000cd91a50ad459b41fe065f7bbab866d5390e945fa").to_vec(),
+            ],
+            data: hex!("0000000000000000000000000000000000000000000000000de0b6b3a7640000").to_vec(),
+            index: 0,
+            block_index: 7,
+            ordinal,
+            ..Default::default()
+        };
+
+        let expected = Event {
+            from: hex!("ab07a50ad459b41fe065f7bbab866d5390e9f705").to_vec(),
+            to: hex!("cd91a50ad459b41fe065f7bbab866d5390e945fa").to_vec(),
+            value: BigInt::from(1_000_000_000_000_000_000u64),
+        };
+
+        assert_eq!(Event::decode_with_ordinal(&log(0)), Ok((expected.clone(), 0)));
+        assert_eq!(Event::decode_with_ordinal(&log(42)), Ok((expected.clone(), 42)));
+        assert_eq!(
+            Event::decode_with_ordinal(&pb::eth::v2::Log {
+                data: vec![],
+                ..log(43)
+            })
+            .is_err(),
+            true
+        );
+
+        assert_eq!(
+            Event::match_and_decode_with_ordinal(&log(42)),
+            Some((expected.clone(), 42))
+        );
+        assert_eq!(
+            Event::match_and_decode_with_ordinal(&pb::eth::v2::Log {
+                topics: vec![
+                    hex!("8c5be1e5ebec7d5bd14f71427d1e84f3dd0314c0f7b2291e5b200ac8c7c3b925").to_vec(),
+                ],
+                ..log(43)
+            }),
+            None
+        );
+
+        let trx = pb::eth::v2::TransactionTrace {
+            status: 1,
+            receipt: Some(pb::eth::v2::TransactionReceipt {
+                logs: vec![log(44)],
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let log_view = trx.receipt().logs().next().unwrap();
+        assert_eq!(Event::decode_with_ordinal(log_view), Ok((expected.clone(), 44)));
+        assert_eq!(
+            Event::match_and_decode_with_ordinal(log_view),
+            Some((expected.clone(), 44))
+        );
+    }
+
+    #[test]
+    fn it_event_field_sol_types() {
+        assert_eq!(
+            erc20::events::Transfer::FIELD_SOL_TYPES,
+            &["address", "address", "uint256"],
+        );
+        assert_eq!(
+            tests::events::EventUTupleAddress::FIELD_SOL_TYPES,
+            &["(address)"],
+        );
+    }
+
     #[test]
     fn it_decode_event_bytes_32_uint_address_idx() {
         use num_traits::Num;
@@ -386,6 +732,85 @@ mod tests {
         );
     }
 
+    #[test]
+    fn it_decode_activity_log_and_call() {
+        use erc20::{events::Events, functions::Calls, Activity};
+
+        let log = pb::eth::v2::Log {
+            topics: vec![
+                hex!("ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef").to_vec(),
+                hex!("000000000000000000000000ab07a50ad459b41fe065f7bbab866d5390e9f705").to_vec(),
+                hex!("000000000000000000000000cd91a50ad459b41fe065f7bbab866d5390e945fa").to_vec(),
+            ],
+            data: hex!("0000000000000000000000000000000000000000000000000de0b6b3a7640000").to_vec(),
+            ..Default::default()
+        };
+
+        let call = pb::eth::v2::Call {
+            input: hex!("a9059cbb000000000000000000000000cd91a50ad459b41fe065f7bbab866d5390e945fa0000000000000000000000000000000000000000000000000de0b6b3a7640000").to_vec(),
+            ..Default::default()
+        };
+
+        match Activity::match_and_decode(&log) {
+            Some(Activity::Event(Events::Transfer(transfer))) => assert_eq!(
+                transfer,
+                erc20::events::Transfer {
+                    from: hex!("ab07a50ad459b41fe065f7bbab866d5390e9f705").to_vec(),
+                    to: hex!("cd91a50ad459b41fe065f7bbab866d5390e945fa").to_vec(),
+                    value: BigInt::from(1_000_000_000_000_000_000u64),
+                }
+            ),
+            _ => panic!("log should have been classified as a Transfer event"),
+        }
+
+        match Activity::match_and_decode(&call) {
+            Some(Activity::Call(Calls::Transfer(transfer))) => assert_eq!(
+                transfer,
+                erc20::functions::Transfer {
+                    to: hex!("cd91a50ad459b41fe065f7bbab866d5390e945fa").to_vec(),
+                    value: BigInt::from(1_000_000_000_000_000_000u64),
+                }
+            ),
+            _ => panic!("call should have been classified as a transfer call"),
+        }
+
+        let unknown_call = pb::eth::v2::Call {
+            input: hex!("095ea7b3").to_vec(),
+            ..Default::default()
+        };
+        assert_eq!(Activity::match_and_decode(&unknown_call).is_none(), true);
+
+        let trx = pb::eth::v2::TransactionTrace {
+            status: 1,
+            receipt: Some(pb::eth::v2::TransactionReceipt {
+                logs: vec![log.clone()],
+                ..Default::default()
+            }),
+            calls: vec![call.clone()],
+            ..Default::default()
+        };
+
+        let log_view = trx.receipt().logs().next().unwrap();
+        assert_eq!(
+            Activity::match_and_decode(&log_view),
+            Activity::match_and_decode(&log)
+        );
+        assert_eq!(
+            Activity::match_and_decode(log_view),
+            Activity::match_and_decode(&log)
+        );
+
+        let call_view = trx.calls().next().unwrap();
+        assert_eq!(
+            Activity::match_and_decode(&call_view),
+            Activity::match_and_decode(&call)
+        );
+        assert_eq!(
+            Activity::match_and_decode(call_view),
+            Activity::match_and_decode(&call)
+        );
+    }
+
     #[test]
     fn it_decode_fun_input_string() {
         use tests::functions::FunString as Function;
@@ -640,6 +1065,38 @@ mod tests {
         );
     }
 
+    #[test]
+    fn it_encode_fun_input_int256_positive() {
+        use substreams::scalar::BigInt;
+        use tests::functions::FunInt256 as Function;
+
+        let fun = Function {
+            param0: BigInt::from(num_bigint::ToBigInt::to_bigint(&9809887317731i64).unwrap()),
+        };
+
+        assert_eq!(
+            fun.encode(),
+            hex!("f70af73b000000000000000000000000000000000000000000000000000008ec0ad94ee3")
+                .to_vec()
+        );
+    }
+
+    #[test]
+    fn it_encode_fun_input_int256_positive_top_bit_set() {
+        use substreams::scalar::BigInt;
+        use tests::functions::FunInt256 as Function;
+
+        let fun = Function {
+            param0: BigInt::from(num_bigint::ToBigInt::to_bigint(&128).unwrap()),
+        };
+
+        assert_eq!(
+            fun.encode(),
+            hex!("f70af73b0000000000000000000000000000000000000000000000000000000000000080")
+                .to_vec()
+        );
+    }
+
     #[test]
     fn it_encode_fun_input_int8_int32_int64_int256() {
         use substreams::scalar::BigInt;
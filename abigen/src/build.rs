@@ -26,6 +26,7 @@ pub struct Abigen<'a> {
 #[derive(Debug, Clone)]
 pub struct AbiExtension {
     event_extension: EventExtension,
+    extended_activity: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -33,16 +34,32 @@ pub struct EventExtension {
     extended_event_derive: Vec<String>,
     extended_event_import: Vec<String>,
     extended_event_attribute: Vec<String>,
+    extended_event_merge: Vec<String>,
+    extended_event_content_hash: bool,
 }
 
 impl AbiExtension {
     pub fn new(event_extension: EventExtension) -> Self {
-        Self { event_extension }
+        Self {
+            event_extension,
+            extended_activity: false,
+        }
     }
 
     pub fn event_extension(&self) -> EventExtension {
         self.event_extension.clone()
     }
+
+    pub fn extended_activity(&self) -> bool {
+        self.extended_activity
+    }
+
+    /// Generates a `functions::Calls` enum dispatching a call to the contract's functions and a
+    /// top-level `Activity` enum unifying `events::Events` and `functions::Calls`, whose
+    /// `match_and_decode` accepts either a `Log` or a `Call`.
+    pub fn extend_activity(&mut self) {
+        self.extended_activity = true;
+    }
 }
 
 impl EventExtension {
@@ -51,6 +68,8 @@ impl EventExtension {
             extended_event_derive: vec![],
             extended_event_import: vec![],
             extended_event_attribute: vec![],
+            extended_event_merge: vec![],
+            extended_event_content_hash: false,
         }
     }
 
@@ -66,6 +85,14 @@ impl EventExtension {
         &self.extended_event_attribute
     }
 
+    pub fn extended_event_merge(&self) -> &Vec<String> {
+        &self.extended_event_merge
+    }
+
+    pub fn extended_event_content_hash(&self) -> bool {
+        self.extended_event_content_hash
+    }
+
     pub fn extend_event_derive(&mut self, derive: &str) {
         self.extended_event_derive.push(derive.to_string());
     }
@@ -78,6 +105,29 @@ impl EventExtension {
         self.extended_event_attribute.push(attribute.to_string());
     }
 
+    /// Generates `match_and_decode_merged` on the event named `event` (as named in the
+    /// generated code, e.g. `TokenSplit` for an ABI event `token_split` or `EventWithOverloads1`
+    /// for overloaded events), which merges consecutive logs of that event into a single value.
+    /// Generation fails if no event has that name.
+    ///
+    /// The generated event must implement `substreams_ethereum::MergeEvent` in your crate,
+    /// it defines the merge rule and the generated code won't compile without it.
+    pub fn extend_event_merge(&mut self, event: &str) {
+        self.extended_event_merge.push(event.to_string());
+    }
+
+    /// Generates `to_log`, re-encoding the event into a log's topics and data, and
+    /// `content_hash`, the keccak256 hash of that re-encoding, on all events. The hash
+    /// identifies an event by its content, independently of the log's position in the block,
+    /// which is useful to deduplicate events across reorgs.
+    ///
+    /// Events that can't be re-encoded are skipped and get neither method: anonymous events
+    /// and events with an indexed `string`, `bytes`, array or tuple param, whose topic is the
+    /// keccak256 hash of the value.
+    pub fn extend_event_content_hash(&mut self) {
+        self.extended_event_content_hash = true;
+    }
+
 }
 
 impl<'a> Abigen<'a> {
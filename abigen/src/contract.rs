@@ -95,6 +95,33 @@ impl Contract {
         }
         self
     }
+    /// Ensures the events named in the extension exist in the contract, a typo would otherwise
+    /// silently generate nothing.
+    pub fn validate_extension(&self) -> Result<(), anyhow::Error> {
+        let extension = match &self.extension {
+            Some(extension) => extension.event_extension(),
+            None => return Ok(()),
+        };
+
+        let names: Vec<_> = self
+            .events
+            .iter()
+            .map(|event| event.generate_camel_name().to_string())
+            .collect();
+
+        for name in extension.extended_event_merge() {
+            if !names.contains(name) {
+                return Err(anyhow::format_err!(
+                    "event `{}` passed to `extend_event_merge` does not exist, known events are: {}",
+                    name,
+                    names.join(", ")
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
     /// Generates rust interface for a contract.
     pub fn generate(&self) -> TokenStream {
         // let constructor = self.constructor.as_ref().map(Constructor::generate);
@@ -134,7 +161,7 @@ impl Contract {
                     .map(|ident| syn::parse_str::<syn::Path>(ident).unwrap())
                     .collect();
                 Some(quote! {
-                    #[derive(#(#ident),*)]
+                    #(,#ident)*
                 })
             } else {
                 None
@@ -157,6 +184,81 @@ impl Contract {
             quote! {}
         };
 
+        let extended_activity = self
+            .extension
+            .as_ref()
+            .map(|extension| extension.extended_activity())
+            .unwrap_or(false);
+
+        let (calls, activity) = if extended_activity {
+            let functions_ident: Vec<_> = self
+                .functions
+                .iter()
+                .map(|function| function.generate_camel_name())
+                .collect();
+
+            let call_contract_check = if let Some(address) = &self.contract_address {
+                quote! {
+                    use hex;
+
+                    if hex::encode(call.address.clone()) != #address {
+                        return None;
+                    }
+                }
+            } else {
+                quote! {}
+            };
+
+            // Function structs only derive the base set, extended event derives can't apply here
+            let calls = quote! {
+                #[derive(Debug, Clone, PartialEq)]
+                pub enum Calls {
+                    #( #functions_ident(#functions_ident), )*
+                }
+
+                impl Calls {
+                    pub fn match_and_decode(call: &substreams_ethereum::pb::eth::v2::Call) -> Option<Calls> {
+                        use substreams_ethereum::Function;
+                           #call_contract_check
+                           #(
+                               if let Some(function) = #functions_ident::match_and_decode(call) {
+                                   return Some(Calls::#functions_ident(function));
+                               }
+                           )*
+                        return None
+                    }
+                }
+            };
+
+            let activity = quote! {
+                /// Contract's events and calls.
+                #[derive(Debug, Clone, PartialEq)]
+                pub enum Activity {
+                    Event(events::Events),
+                    Call(functions::Calls),
+                }
+
+                impl Activity {
+                    pub fn match_and_decode<'a>(
+                        activity: impl Into<substreams_ethereum::ActivityRef<'a>>,
+                    ) -> Option<Activity> {
+                        match activity.into() {
+                            substreams_ethereum::ActivityRef::Log(log) => {
+                                events::Events::match_and_decode(log).map(Activity::Event)
+                            }
+                            substreams_ethereum::ActivityRef::Call(call) => {
+                                functions::Calls::match_and_decode(call).map(Activity::Call)
+                            }
+                        }
+                    }
+                }
+            };
+
+            (calls, activity)
+        } else {
+            (quote! {}, quote! {})
+        };
+
 
         quote! {
 
@@ -169,6 +271,9 @@ impl Contract {
             #[allow(dead_code, unused_imports, unused_variables)]
             pub mod functions {
                 use super::INTERNAL_ERR;
+
+                #calls
+
                 #(#functions)*
             }
 
@@ -177,7 +282,7 @@ impl Contract {
             pub mod events {
                 use super::INTERNAL_ERR;
 
-                #derive
+                #[derive(Debug, Clone, PartialEq #derive)]
                 pub enum Events {
                     #( #events_ident(#events_ident), )*
                 }
@@ -194,6 +299,8 @@ impl Contract {
 
                 #(#events)*
             }
+
+            #activity
         }
     }
 }
@@ -238,4 +345,42 @@ mod test {
             },
         );
     }
+
+    #[test]
+    fn test_extend_event_merge_uses_generated_name() {
+        use crate::build::{AbiExtension, EventExtension};
+
+        let mut ethabi_contract = ethabi::Contract {
+            constructor: None,
+            functions: Default::default(),
+            events: Default::default(),
+            errors: Default::default(),
+            receive: false,
+            fallback: false,
+        };
+        ethabi_contract.events.insert(
+            "token_split".into(),
+            vec![ethabi::Event {
+                name: "token_split".into(),
+                inputs: vec![],
+                anonymous: false,
+            }],
+        );
+
+        let contract = |merged: &str| {
+            let mut event_extension = EventExtension::new();
+            event_extension.extend_event_merge(merged);
+
+            Contract::from(&ethabi_contract)
+                .add_extension(Some(AbiExtension::new(event_extension)))
+        };
+
+        let c = contract("TokenSplit");
+        assert!(c.validate_extension().is_ok());
+        assert!(c.generate().to_string().contains("match_and_decode_merged"));
+
+        let c = contract("token_split");
+        assert!(c.validate_extension().is_err());
+        assert!(!c.generate().to_string().contains("match_and_decode_merged"));
+    }
 }
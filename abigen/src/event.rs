@@ -4,19 +4,27 @@ use quote::quote;
 
 use crate::{build::EventExtension, decode_topic, fixed_data_size, min_data_size};
 
-use super::{from_token, rust_type, to_syntax_string};
+use super::{from_token, rust_type, to_syntax_string, to_token};
 
 /// Structure used to generate contract's event interface.
 pub struct Event {
     pub(crate) name: String,
     topic_hash: [u8; 32],
+    anonymous: bool,
     topic_count: usize,
     min_data_size: usize,
     fixed_data_size: Option<usize>,
     log_fields: Vec<TokenStream>,
+    field_sol_types: Vec<String>,
     decode_indexed_fields: Vec<TokenStream>,
     decode_unindexed_fields: Vec<TokenStream>,
     decode_data: TokenStream,
+    /// `None` if the event can't be re-encoded into a log, see
+    /// `EventExtension::extend_event_content_hash`.
+    encode_topics: Option<Vec<TokenStream>>,
+    encode_data: Vec<TokenStream>,
+    /// Names of the `from` and `to` fields if the event is an ERC20 `Transfer`.
+    erc20_transfer: Option<(syn::Ident, syn::Ident)>,
 
     extension: Option<EventExtension>,
 }
@@ -66,6 +74,8 @@ impl<'a> From<(&'a String, &'a ethabi::Event)> for Event {
             .map(|param| rust_type(&param.kind))
             .collect();
 
+        let field_sol_types = e.inputs.iter().map(|param| param.kind.to_string()).collect();
+
         let log_fields = names
             .iter()
             .zip(kinds.iter())
@@ -123,16 +133,54 @@ impl<'a> From<(&'a String, &'a ethabi::Event)> for Event {
             })
             .collect();
 
+        // Indexed reference types are hashed into their topic, the value can't be re-encoded
+        // from it, neither can anonymous events, they have no signature topic.
+        let encode_topics = if e.anonymous
+            || e.inputs
+                .iter()
+                .any(|param| param.indexed && is_hashed_topic(&param.kind))
+        {
+            None
+        } else {
+            Some(
+                e.inputs
+                    .iter()
+                    .zip(names.iter())
+                    .filter(|(param, _)| param.indexed)
+                    .map(|(param, name)| to_token(&quote! { self.#name }, &param.kind))
+                    .collect(),
+            )
+        };
+
+        let encode_data = e
+            .inputs
+            .iter()
+            .zip(names.iter())
+            .filter(|(param, _)| !param.indexed)
+            .map(|(param, name)| to_token(&quote! { self.#name }, &param.kind))
+            .collect();
+
+        let erc20_transfer = if is_erc20_transfer(e) {
+            Some((names[0].clone(), names[1].clone()))
+        } else {
+            None
+        };
+
         Event {
             name: name.clone(),
             topic_hash: e.signature().to_fixed_bytes(),
+            anonymous: e.anonymous,
             topic_count,
             fixed_data_size,
             min_data_size,
             log_fields,
+            field_sol_types,
             decode_indexed_fields,
             decode_unindexed_fields,
             decode_data,
+            encode_topics,
+            encode_data,
+            erc20_transfer,
             extension: None,
         }
     }
@@ -142,6 +190,7 @@ impl Event {
     /// Generates rust interface for contract's event.
     pub fn generate_event(&self) -> TokenStream {
         let name = &self.name;
+        let anonymous = &self.anonymous;
         let topic_count = &self.topic_count;
         let topic_hash_bytes: Vec<_> = self
             .topic_hash
@@ -150,6 +199,7 @@ impl Event {
             .collect();
         let camel_name = self.generate_camel_name();
         let log_fields = &self.log_fields;
+        let field_sol_types = &self.field_sol_types;
 
         let decode_data = &self.decode_data;
         let mut decode_fields = Vec::with_capacity(
@@ -210,6 +260,60 @@ impl Event {
         };
 
 
+        let merge_decode = match &self.extension {
+            Some(extension)
+                if extension
+                    .extended_event_merge()
+                    .contains(&self.generate_camel_name().to_string()) =>
+            {
+                quote! {
+                    pub fn match_and_decode_merged<L: AsRef<substreams_ethereum::pb::eth::v2::Log>>(
+                        logs: impl IntoIterator<Item = L>,
+                    ) -> Vec<Self> {
+                        substreams_ethereum::merge_consecutive(logs)
+                    }
+                }
+            }
+            _ => quote! {},
+        };
+
+        let mint_burn = match &self.erc20_transfer {
+            Some((from, to)) => quote! {
+                pub fn is_mint(&self) -> bool {
+                    substreams_ethereum::is_null_address(&self.#from)
+                }
+
+                pub fn is_burn(&self) -> bool {
+                    substreams_ethereum::is_null_address(&self.#to)
+                }
+            },
+            None => quote! {},
+        };
+
+        let content_hash = match (&self.extension, &self.encode_topics) {
+            (Some(extension), Some(encode_topics)) if extension.extended_event_content_hash() => {
+                let encode_data = &self.encode_data;
+
+                quote! {
+                    pub fn to_log(&self) -> substreams_ethereum::pb::eth::v2::Log {
+                        substreams_ethereum::pb::eth::v2::Log {
+                            topics: vec![
+                                Self::TOPIC_ID.to_vec(),
+                                #(ethabi::encode(&[#encode_topics]),)*
+                            ],
+                            data: ethabi::encode(&[#(#encode_data),*]),
+                            ..Default::default()
+                        }
+                    }
+
+                    pub fn content_hash(&self) -> [u8; 32] {
+                        substreams_ethereum::log_content_hash(&self.to_log())
+                    }
+                }
+            }
+            _ => quote! {},
+        };
+
         let min_data_size = &self.min_data_size;
         let log_match_data = match &self.fixed_data_size {
             Some(fixed_data_size) => {
@@ -239,6 +343,7 @@ impl Event {
 
             impl #camel_name {
                 const TOPIC_ID: [u8; 32] = [#(#topic_hash_bytes),*];
+                pub const FIELD_SOL_TYPES: &'static [&'static str] = &[#(#field_sol_types),*];
 
                 pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
                     if log.topics.len() != #topic_count {
@@ -258,6 +363,12 @@ impl Event {
                         #(#decode_fields),*
                     })
                 }
+
+                #mint_burn
+
+                #content_hash
+
+                #merge_decode
             }
 
             impl substreams_ethereum::Event for #camel_name {
@@ -269,6 +380,11 @@ impl Event {
                     Self::decode(log)
                 }
             }
+
+            impl substreams_ethereum::EventSignature for #camel_name {
+                const TOPIC_ID: [u8; 32] = Self::TOPIC_ID;
+                const ANONYMOUS: bool = #anonymous;
+            }
         }
     }
 
@@ -281,6 +397,43 @@ impl Event {
     }
 }
 
+/// Returns `true` if an indexed param of this kind is stored in its topic as the keccak256
+/// hash of its value rather than the value itself.
+fn is_hashed_topic(kind: &ethabi::ParamType) -> bool {
+    use ethabi::ParamType;
+
+    matches!(
+        kind,
+        ParamType::Bytes
+            | ParamType::String
+            | ParamType::Array(_)
+            | ParamType::FixedArray(_, _)
+            | ParamType::Tuple(_)
+    )
+}
+
+/// Returns `true` if the event is shaped like an ERC20 `Transfer(address indexed from, address
+/// indexed to, uint256 value)`, parameter names are not part of the check. ERC721 `Transfer`
+/// shares the same signature but has its third parameter indexed, so it's not matched.
+fn is_erc20_transfer(e: &ethabi::Event) -> bool {
+    use ethabi::ParamType;
+
+    let shape: Vec<_> = e
+        .inputs
+        .iter()
+        .map(|param| (&param.kind, param.indexed))
+        .collect();
+
+    !e.anonymous
+        && e.name == "Transfer"
+        && shape
+            == [
+                (&ParamType::Address, true),
+                (&ParamType::Address, true),
+                (&ParamType::Uint(256), false),
+            ]
+}
+
 #[cfg(test)]
 mod tests {
     use crate::assertions::assert_ast_eq;
@@ -338,6 +491,7 @@ mod tests {
                         16u8,
                         101u8
                     ];
+                    pub const FIELD_SOL_TYPES: &'static [&'static str] = &[];
                     pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
                         if log.topics.len() != 1usize {
                             return false;
@@ -363,6 +517,10 @@ mod tests {
                         Self::decode(log)
                     }
                 }
+                impl substreams_ethereum::EventSignature for Hello {
+                    const TOPIC_ID: [u8; 32] = Self::TOPIC_ID;
+                    const ANONYMOUS: bool = false;
+                }
             },
         );
     }
@@ -423,6 +581,7 @@ mod tests {
                         22u8,
                         66u8
                     ];
+                    pub const FIELD_SOL_TYPES: &'static [&'static str] = &["address"];
                     pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
                         if log.topics.len() != 2usize {
                             return false;
@@ -463,6 +622,10 @@ mod tests {
                         Self::decode(log)
                     }
                 }
+                impl substreams_ethereum::EventSignature for One {
+                    const TOPIC_ID: [u8; 32] = Self::TOPIC_ID;
+                    const ANONYMOUS: bool = false;
+                }
             },
         );
     }
@@ -537,6 +700,7 @@ mod tests {
                         179u8,
                         239u8
                     ];
+                    pub const FIELD_SOL_TYPES: &'static [&'static str] = &["address", "address", "uint256"];
                     pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
                         if log.topics.len() != 3usize {
                             return false;
@@ -596,6 +760,12 @@ mod tests {
                             }
                         })
                     }
+                    pub fn is_mint(&self) -> bool {
+                        substreams_ethereum::is_null_address(&self.from)
+                    }
+                    pub fn is_burn(&self) -> bool {
+                        substreams_ethereum::is_null_address(&self.to)
+                    }
                 }
                 impl substreams_ethereum::Event for Transfer {
                     const NAME: &'static str = "Transfer";
@@ -606,6 +776,10 @@ mod tests {
                         Self::decode(log)
                     }
                 }
+                impl substreams_ethereum::EventSignature for Transfer {
+                    const TOPIC_ID: [u8; 32] = Self::TOPIC_ID;
+                    const ANONYMOUS: bool = false;
+                }
             },
         );
     }
@@ -680,6 +854,7 @@ mod tests {
                         179u8,
                         239u8
                     ];
+                    pub const FIELD_SOL_TYPES: &'static [&'static str] = &["address", "address", "uint256"];
                     pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
                         if log.topics.len() != 4usize {
                             return false;
@@ -750,7 +925,36 @@ mod tests {
                         Self::decode(log)
                     }
                 }
+                impl substreams_ethereum::EventSignature for Transfer {
+                    const TOPIC_ID: [u8; 32] = Self::TOPIC_ID;
+                    const ANONYMOUS: bool = false;
+                }
             },
         );
     }
+
+    #[test]
+    fn test_event_encode_topics_skips_hashed_topics_and_anonymous() {
+        let event = |kind: ethabi::ParamType, anonymous: bool| ethabi::Event {
+            name: "hello".into(),
+            inputs: vec![ethabi::EventParam {
+                name: "first".into(),
+                kind,
+                indexed: true,
+            }],
+            anonymous,
+        };
+
+        let encodable = event(ethabi::ParamType::Address, false);
+        let e = Event::from((&encodable.name, &encodable));
+        assert!(e.encode_topics.is_some());
+
+        let hashed_topic = event(ethabi::ParamType::String, false);
+        let e = Event::from((&hashed_topic.name, &hashed_topic));
+        assert!(e.encode_topics.is_none());
+
+        let anonymous = event(ethabi::ParamType::Address, true);
+        let e = Event::from((&anonymous.name, &anonymous));
+        assert!(e.encode_topics.is_none());
+    }
 }
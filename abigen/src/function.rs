@@ -7,7 +7,7 @@
 // except according to those terms.
 
 use heck::ToUpperCamelCase;
-use proc_macro2::{Span, TokenStream};
+use proc_macro2::{Ident, Span, TokenStream};
 use quote::quote;
 
 use crate::to_syntax_string;
@@ -199,7 +199,7 @@ impl Function {
     /// Generates the interface for contract's function.
     pub fn generate(&self) -> TokenStream {
         let name = &self.name;
-        let camel_name = syn::Ident::new(&self.name.to_upper_camel_case(), Span::call_site());
+        let camel_name = self.generate_camel_name();
 
         let signature_hash_bytes: Vec<_> = self
             .short_signature
@@ -318,4 +318,8 @@ impl Function {
             #rpc_decodable_implementation
         }
     }
+
+    pub fn generate_camel_name(&self) -> Ident {
+        syn::Ident::new(&self.name.to_upper_camel_case(), Span::call_site())
+    }
 }
@@ -50,6 +50,7 @@ pub fn generate_abi_code<S: AsRef<str>>(
         .add_extension(extension)
         .add_contract_name(contract_name)
         .add_contract_address(contract_address);
+    c.validate_extension()?;
 
     Ok(c.generate())
 }
@@ -66,6 +67,7 @@ pub fn generate_abi_code_from_bytes(
         .add_extension(extension)
         .add_contract_name(contract_name)
         .add_contract_address(contract_address);
+    c.validate_extension()?;
 
     Ok(c.generate())
 }
@@ -265,7 +267,12 @@ fn to_token(name: &proc_macro2::TokenStream, kind: &ParamType) -> proc_macro2::T
             quote! {
                 {
                     let non_full_signed_bytes = #name.to_signed_bytes_be();
-                    let mut full_signed_bytes = [0xff as u8; 32];
+                    // Sign-extend to 32 bytes, 0xff padding for negative values, 0x00 otherwise
+                    let sign_byte = match non_full_signed_bytes.first() {
+                        Some(byte) if byte & 0x80 == 0x80 => 0xffu8,
+                        _ => 0x00u8,
+                    };
+                    let mut full_signed_bytes = [sign_byte; 32];
                     non_full_signed_bytes.into_iter().rev().enumerate().for_each(|(i, byte)| full_signed_bytes[31 - i] = byte);
 
                     ethabi::Token::Int(ethabi::Int::from_big_endian(full_signed_bytes.as_ref()))
@@ -0,0 +1,46 @@
+use crate::block_view::{CallView, LogView};
+use crate::pb::eth::v2::{Call, Log};
+
+/// Either a log or a call of a block, accepted by the generated `Activity::match_and_decode`
+/// to classify a contract's on-chain activity through a single entry point.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ActivityRef<'a> {
+    Log(&'a Log),
+    Call(&'a Call),
+}
+
+impl<'a> From<&'a Log> for ActivityRef<'a> {
+    fn from(log: &'a Log) -> Self {
+        ActivityRef::Log(log)
+    }
+}
+
+impl<'a> From<&'a Call> for ActivityRef<'a> {
+    fn from(call: &'a Call) -> Self {
+        ActivityRef::Call(call)
+    }
+}
+
+impl<'a> From<LogView<'a>> for ActivityRef<'a> {
+    fn from(view: LogView<'a>) -> Self {
+        ActivityRef::Log(view.log)
+    }
+}
+
+impl<'a> From<&LogView<'a>> for ActivityRef<'a> {
+    fn from(view: &LogView<'a>) -> Self {
+        ActivityRef::Log(view.log)
+    }
+}
+
+impl<'a> From<CallView<'a>> for ActivityRef<'a> {
+    fn from(view: CallView<'a>) -> Self {
+        ActivityRef::Call(view.call)
+    }
+}
+
+impl<'a> From<&CallView<'a>> for ActivityRef<'a> {
+    fn from(view: &CallView<'a>) -> Self {
+        ActivityRef::Call(view.call)
+    }
+}
@@ -1,3 +1,8 @@
+use std::any::Any;
+use std::fmt::Debug;
+
+use tiny_keccak::{Hasher, Keccak};
+
 use crate::pb::eth::v2::Log;
 
 pub trait Event: Sized {
@@ -6,6 +11,31 @@ pub trait Event: Sized {
     fn match_log(log: &Log) -> bool;
     fn decode(log: &Log) -> Result<Self, String>;
 
+    /// Decodes the log like [Event::decode] and returns the decoded event along with the
+    /// log's `ordinal`.
+    ///
+    /// The ordinal orders all items of a block (logs, calls, balance changes, etc.)
+    /// deterministically, unlike `log.index` or `log.block_index` which only order logs among
+    /// themselves. Pass it as the `ord` argument of store operations (e.g.
+    /// `store.set(ordinal, key, value)`), reads like `store.get_at(ordinal, key)` then return the
+    /// value as of that point in the block which is only correct if writes are done in
+    /// increasing ordinal order.
+    ///
+    /// Like [Event::decode], the log must have been checked with [Event::match_log] first,
+    /// decoding a log of another event can panic. Use [Event::match_and_decode_with_ordinal]
+    /// when iterating over all logs of a block.
+    fn decode_with_ordinal(log: impl AsRef<Log>) -> Result<(Self, u64), String> {
+        let log = log.as_ref();
+        Self::decode(log).map(|event| (event, log.ordinal))
+    }
+
+    /// Attempts to match and decode the log like [Event::match_and_decode] and returns the
+    /// decoded event along with the log's `ordinal`, see [Event::decode_with_ordinal].
+    fn match_and_decode_with_ordinal(log: impl AsRef<Log>) -> Option<(Self, u64)> {
+        let log = log.as_ref();
+        Self::match_and_decode(log).map(|event| (event, log.ordinal))
+    }
+
     /// Attempts to match and decode the log.
     /// If `Self::match_log(log)` is `false`, returns `None`.
     /// If it matches, but decoding fails, logs the decoding error and returns `None`.
@@ -30,8 +60,186 @@ pub trait Event: Sized {
     }
 }
 
+/// Returns the keccak256 hash of the log's topics followed by its data.
+///
+/// The topics count and each part's length are hashed too, so that moving bytes between
+/// topics and data always changes the hash.
+///
+/// Only the event's content is hashed, the log's address and position in the block (index,
+/// ordinal) are not part of it, so the hash stays the same for an event re-emitted in another
+/// block after a reorg.
+pub fn log_content_hash(log: &Log) -> [u8; 32] {
+    let mut keccak = Keccak::v256();
+    keccak.update(&(log.topics.len() as u64).to_be_bytes());
+    for topic in log.topics.iter() {
+        keccak.update(&(topic.len() as u64).to_be_bytes());
+        keccak.update(topic);
+    }
+    keccak.update(&(log.data.len() as u64).to_be_bytes());
+    keccak.update(&log.data);
+
+    let mut hash = [0u8; 32];
+    keccak.finalize(&mut hash);
+    hash
+}
+
+/// Signature of an event as declared in its ABI, implemented by generated events.
+pub trait EventSignature: Event {
+    /// The keccak256 hash of the event's signature, found in `log.topics[0]` unless the event
+    /// is [anonymous](EventSignature::ANONYMOUS).
+    const TOPIC_ID: [u8; 32];
+
+    /// `true` if the event is declared `anonymous`, its logs then have no signature topic.
+    const ANONYMOUS: bool;
+}
+
+/// Object-safe counterpart of [Event] so that decoded events of different types can be
+/// handled together as `Box<dyn DynEvent>`, see [EventRegistry](crate::EventRegistry).
+///
+/// It's implemented for every [EventSignature], use `downcast_ref` (or [DynEvent::into_any]
+/// to take ownership) to retrieve the concrete event.
+pub trait DynEvent: Any + Debug {
+    /// Returns [Event::NAME] of the decoded event.
+    fn name(&self) -> &'static str;
+
+    /// Returns [EventSignature::TOPIC_ID] of the decoded event.
+    fn topic_id(&self) -> [u8; 32];
+
+    fn as_any(&self) -> &dyn Any;
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any>;
+}
+
+impl<E: EventSignature + Debug + 'static> DynEvent for E {
+    fn name(&self) -> &'static str {
+        E::NAME
+    }
+
+    fn topic_id(&self) -> [u8; 32] {
+        E::TOPIC_ID
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+
+impl dyn DynEvent {
+    /// Returns the decoded event as `E` if it's of this type, `None` otherwise.
+    pub fn downcast_ref<E: DynEvent>(&self) -> Option<&E> {
+        self.as_any().downcast_ref()
+    }
+
+    /// Returns `true` if the decoded event is of type `E`.
+    pub fn is<E: DynEvent>(&self) -> bool {
+        self.as_any().is::<E>()
+    }
+}
+
+/// Merge rule for an event that a protocol splits across consecutive logs, for example
+/// a batched emission where a single logical value is emitted in several chunks.
+///
+/// Implement it on the generated event struct and use [merge_consecutive] (or the
+/// generated `match_and_decode_merged` when the event was opted in through
+/// `EventExtension::extend_event_merge`) to fold the chunks back into a single value.
+pub trait MergeEvent: Event {
+    /// Returns `true` if `next`, decoded from the log directly following the log(s) that
+    /// produced `self`, is a continuation of `self` and must be merged into it.
+    fn should_merge(&self, next: &Self) -> bool;
+
+    /// Folds `next` into `self`, only called when [MergeEvent::should_merge] returned `true`.
+    fn merge(&mut self, next: Self);
+}
+
+/// Decodes each log of `logs` as `E` and merges runs of consecutive matching logs into a
+/// single value using the [MergeEvent] rule of `E`.
+///
+/// This is a stateful pass over the whole log stream, so the following constraints apply:
+///
+/// - Logs must be given in emission order (e.g. as returned by `block.logs()` or sorted by
+///   `ordinal`), merging is only attempted between adjacent logs.
+/// - A log that does not match `E` (or matches but fails to decode) ends the current run,
+///   events separated by any other log are never merged together.
+/// - Only logs emitted by the same contract are merged, a change of `log.address` ends the
+///   current run before [MergeEvent::should_merge] is even consulted.
+/// - Runs are not split at transaction boundaries, with `block.logs()` the last log of a
+///   transaction can be merged with the first log of the next one. Feed each transaction's
+///   `receipt().logs()` separately to keep runs within a single transaction.
+/// - The last run is flushed once `logs` is exhausted, the returned values are in the
+///   order their first log was seen.
+pub fn merge_consecutive<E: MergeEvent, L: AsRef<Log>>(
+    logs: impl IntoIterator<Item = L>,
+) -> Vec<E> {
+    let mut merged = Vec::new();
+    // The event being merged along with the address of the contract that emitted it
+    let mut pending: Option<(E, Vec<u8>)> = None;
+
+    for log in logs {
+        let log = log.as_ref();
+        let event = match E::match_and_decode(log) {
+            Some(event) => event,
+            None => {
+                merged.extend(pending.take().map(|(current, _)| current));
+                continue;
+            }
+        };
+
+        pending = match pending {
+            Some((mut current, address))
+                if address == log.address && current.should_merge(&event) =>
+            {
+                current.merge(event);
+                Some((current, address))
+            }
+            Some((current, _)) => {
+                merged.push(current);
+                Some((event, log.address.clone()))
+            }
+            None => Some((event, log.address.clone())),
+        };
+    }
+
+    merged.extend(pending.map(|(current, _)| current));
+    merged
+}
+
 impl AsRef<Log> for Log {
     fn as_ref(&self) -> &Self {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::pb::eth::v2::Log;
+
+    use super::log_content_hash;
+
+    #[test]
+    fn test_log_content_hash_frames_topics_and_data() {
+        let log = |topics: Vec<Vec<u8>>, data: Vec<u8>| Log {
+            topics,
+            data,
+            ..Default::default()
+        };
+
+        let reference = log_content_hash(&log(vec![vec![1u8; 32], vec![2u8; 32]], vec![]));
+
+        assert_ne!(
+            reference,
+            log_content_hash(&log(vec![vec![1u8; 32]], vec![2u8; 32]))
+        );
+        assert_ne!(
+            reference,
+            log_content_hash(&log(vec![[vec![1u8; 32], vec![2u8; 32]].concat()], vec![]))
+        );
+        assert_eq!(
+            reference,
+            log_content_hash(&log(vec![vec![1u8; 32], vec![2u8; 32]], vec![]))
+        );
+    }
+}
@@ -3,13 +3,19 @@ pub mod rpc;
 
 /// Helpers to deal with block sources.
 pub mod block_view;
-pub use event::Event;
+pub use activity::ActivityRef;
+pub use event::{
+    log_content_hash, merge_consecutive, DynEvent, Event, EventSignature, MergeEvent,
+};
 pub use function::Function;
+pub use registry::EventRegistry;
 pub mod scalar;
 
+mod activity;
 mod event;
 mod externs;
 mod function;
+mod registry;
 
 /// Represents the null address static array in bytes (20 bytes) which in hex is equivalent
 /// to:
@@ -21,3 +27,9 @@ pub const NULL_ADDRESS: [u8; 20] = [
     0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
     0u8,
 ];
+
+/// Returns `true` if `address` is the [NULL_ADDRESS], for example the `from` of an ERC20
+/// `Transfer` minting tokens.
+pub fn is_null_address<A: AsRef<[u8]>>(address: A) -> bool {
+    address.as_ref() == NULL_ADDRESS
+}
@@ -0,0 +1,119 @@
+use std::collections::HashMap;
+use std::fmt::Debug;
+
+use crate::{
+    event::{DynEvent, EventSignature},
+    pb::eth::v2::Log,
+    Event,
+};
+
+type Decoder = fn(&Log) -> Option<Box<dyn DynEvent>>;
+
+/// A set of events, possibly generated from different ABIs, keyed by their topic0 so that
+/// any log can be decoded against the whole set with a single lookup.
+///
+/// This is the dynamic counterpart of the generated per-contract `Events::match_and_decode`,
+/// useful when the set of events to index is only known at runtime:
+///
+/// ```ignore
+///     let mut registry = EventRegistry::new();
+///     registry
+///         .register::<erc20::events::Transfer>()
+///         .register::<uniswap::events::Swap>();
+///
+///     for log in block.logs() {
+///         if let Some(event) = registry.decode(log) {
+///             if let Some(transfer) = event.downcast_ref::<erc20::events::Transfer>() {
+///                 // ...
+///             }
+///         }
+///     }
+/// ```
+///
+/// Events sharing the same topic0 (e.g. ERC20 and ERC721 `Transfer`) can all be registered,
+/// they are tried in registration order and the first one matching the log wins.
+#[derive(Default)]
+pub struct EventRegistry {
+    decoders: HashMap<[u8; 32], Vec<Decoder>>,
+}
+
+impl EventRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers event `E` under its [EventSignature::TOPIC_ID].
+    ///
+    /// # Panics
+    ///
+    /// If `E` is anonymous, its logs don't carry its signature in topic0 so it can't be looked
+    /// up by topic0.
+    pub fn register<E: EventSignature + Debug + 'static>(&mut self) -> &mut Self {
+        assert!(
+            !E::ANONYMOUS,
+            "event `{}` is anonymous and cannot be registered by topic0",
+            E::NAME
+        );
+
+        self.decoders
+            .entry(E::TOPIC_ID)
+            .or_default()
+            .push(decode_boxed::<E>);
+        self
+    }
+
+    /// Returns `true` if at least one registered event has `topic_id` as its topic0.
+    pub fn contains(&self, topic_id: &[u8; 32]) -> bool {
+        self.decoders.contains_key(topic_id)
+    }
+
+    /// Attempts to match and decode the log against the registered events.
+    /// If no registered event matches the log, returns `None`.
+    /// If one matches, but decoding fails, logs the decoding error and returns `None`.
+    pub fn decode(&self, log: impl AsRef<Log>) -> Option<Box<dyn DynEvent>> {
+        let log = log.as_ref();
+        let topic_id: [u8; 32] = log.topics.first()?.as_slice().try_into().ok()?;
+
+        self.decoders
+            .get(&topic_id)?
+            .iter()
+            .find_map(|decode| decode(log))
+    }
+}
+
+fn decode_boxed<E: EventSignature + Debug + 'static>(log: &Log) -> Option<Box<dyn DynEvent>> {
+    E::match_and_decode(log).map(|event| Box::new(event) as Box<dyn DynEvent>)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{pb::eth::v2::Log, Event, EventSignature};
+
+    use super::EventRegistry;
+
+    #[derive(Debug)]
+    struct Anonymous;
+
+    impl Event for Anonymous {
+        const NAME: &'static str = "Anonymous";
+
+        fn match_log(_log: &Log) -> bool {
+            true
+        }
+
+        fn decode(_log: &Log) -> Result<Self, String> {
+            Ok(Anonymous)
+        }
+    }
+
+    impl EventSignature for Anonymous {
+        const TOPIC_ID: [u8; 32] = [0u8; 32];
+        const ANONYMOUS: bool = true;
+    }
+
+    #[test]
+    #[should_panic(expected = "event `Anonymous` is anonymous")]
+    fn register_anonymous_event() {
+        EventRegistry::new().register::<Anonymous>();
+    }
+}
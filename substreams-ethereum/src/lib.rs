@@ -1,6 +1,9 @@
 
 pub use substreams_ethereum_core::scalar;
-pub use substreams_ethereum_core::{block_view, pb, rpc, Event, Function, NULL_ADDRESS};
+pub use substreams_ethereum_core::{
+    block_view, is_null_address, log_content_hash, merge_consecutive, pb, rpc, ActivityRef,
+    DynEvent, Event, EventRegistry, EventSignature, Function, MergeEvent, NULL_ADDRESS,
+};
 pub use substreams_ethereum_derive::EthabiContract;
 
 #[cfg(all(target_arch = "wasm32", target_os = "unknown"))]